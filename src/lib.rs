@@ -1,16 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![feature(
-    box_into_raw_non_null,
+    allocator_api,
     const_generics,
+    raw_ref_macros,
     type_alias_impl_trait,
 )]
 
-use ::std::{
+extern crate alloc as alloc_crate;
+
+use ::alloc_crate::alloc::{self,
+    Global,
+};
+use ::core::{
+    alloc::{
+        Allocator,
+        Layout,
+    },
     cell::{
         Cell,
         UnsafeCell,
     },
     iter::{self,
         FromIterator,
+        FusedIterator,
     },
     mem::{self,
         MaybeUninit,
@@ -21,45 +33,73 @@ use ::std::{
 
 type NullablePtr<T> = Option<ptr::NonNull<T>>;
 
+// Returned by the fallible `try_*` methods when the list's allocator fails
+// to satisfy the requested allocation.
+#[derive(Debug)]
+pub
+struct TryReserveError(());
+
 struct Chunk<T, const CHUNK_CAPACITY: usize> {
     next: Cell<NullablePtr< Chunk<T, CHUNK_CAPACITY> >>,
     len: Cell<usize>,
     buffer: [UnsafeCell<MaybeUninit<T>>; CHUNK_CAPACITY]
 }
 impl<T, const CHUNK_CAPACITY: usize> Chunk<T, CHUNK_CAPACITY> {
-    fn new (first_elem: T) -> Box<Self>
+    fn try_new<A : Allocator> (alloc: &'_ A, first_elem: T)
+      -> Result<ptr::NonNull<Self>, (T, TryReserveError)>
     {
         assert_ne!(CHUNK_CAPACITY, 0, "chunks CHUNK_capacity cannot be NULL!");
-        let mut ret = Box::new(Self {
-            next: Cell::new(None),
-            len: Cell::new(1),
-            buffer: unsafe {
-                // # Safety
-                // 
-                //   - it is sound to have an uninitialized array of `MaybeUninit`s.
-                MaybeUninit::uninit().assume_init()
-            },
-        });
-        ret.buffer[0] = UnsafeCell::new(MaybeUninit::new(first_elem));
-        ret
+        let layout = Layout::new::<Self>();
+        let raw = match alloc.allocate(layout) {
+            Ok(it) => it.cast::<Self>(),
+            Err(_) => return Err((first_elem, TryReserveError(()))),
+        };
+        unsafe {
+            // # Safety
+            //
+            //   - `raw` points to a fresh `layout`-sized and -aligned allocation
+            //     handed to us by `alloc`; writing to `next` and `len` through
+            //     `addr_of_mut!` projections does not require the rest of `Self`
+            //     (namely `buffer`) to be initialized yet.
+            ptr::addr_of_mut!((*raw.as_ptr()).next).write(Cell::new(None));
+            ptr::addr_of_mut!((*raw.as_ptr()).len).write(Cell::new(1));
+            // # Safety
+            //
+            //   - `buffer` is allowed to stay uninitialized past index `0`, since
+            //     it is an array of `MaybeUninit`s; we own the fresh allocation
+            //     exclusively, so writing through the raw pointer is sound.
+            (*raw.as_ptr()).buffer[0] = UnsafeCell::new(MaybeUninit::new(first_elem));
+        }
+        Ok(raw)
     }
 }
 
 pub
-struct List<T, const CHUNK_CAPACITY: usize> {
+struct List<T, const CHUNK_CAPACITY: usize, A : Allocator = Global> {
     head: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
     last: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
+    alloc: A,
 }
 
-impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY> {
+impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY, Global> {
     #[inline]
     pub
     fn new () -> Self
+    {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> List<T, CHUNK_CAPACITY, A> {
+    #[inline]
+    pub
+    fn new_in (alloc: A) -> Self
     {
         assert_ne!(CHUNK_CAPACITY, 0, "CHUNK_CAPACITY cannot be nul!");
         Self {
             head: None,
             last: None,
+            alloc,
         }
     }
 
@@ -68,14 +108,22 @@ impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY> {
     {
         Some(unsafe {
             // # Safety
-            // 
+            //
             //   - the ptr is valid since it is the safety invariant of Self
             self.last.as_ref()?.as_ref()
         })
     }
-    
+
     pub
     fn push (self: &'_ mut Self, elem: T)
+    {
+        if let Err((_elem, _err)) = self.try_push(elem) {
+            alloc::handle_alloc_error(Layout::new::<Chunk<T, CHUNK_CAPACITY>>());
+        }
+    }
+
+    pub
+    fn try_push (self: &'_ mut Self, elem: T) -> Result<(), (T, TryReserveError)>
     {
         if let Some(last) = self.last() {
             let len = last.len.get();
@@ -93,39 +141,72 @@ impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY> {
                     *last.buffer[len].get() = MaybeUninit::new(elem);
                 }
                 last.len.set(len + 1);
-            } else {
-                let ptr = Some(Box::into_raw_non_null(
-                    Chunk::new(elem)
-                ));
-                last.next.set(ptr);
-                self.last = ptr;
+                return Ok(());
             }
+        }
+        // No room in the current last chunk (or no chunk at all yet): grow.
+        let ptr = Some(match Chunk::try_new(&self.alloc, elem) {
+            Ok(it) => it,
+            Err(err) => return Err(err),
+        });
+        if let Some(last) = self.last() {
+            last.next.set(ptr);
         } else {
-            let ptr = Some(Box::into_raw_non_null(
-                Chunk::new(elem)
-            ));
-            self.last = ptr;
             self.head = ptr;
         }
+        self.last = ptr;
+        Ok(())
     }
 
+    // # Safety note (not statically or dynamically checked)
+    //
+    //   `other`'s chunks end up spliced into `self`'s chain and will later be
+    //   freed through `self.alloc`, so the caller must ensure `self` and
+    //   `other` share the same allocator *instance* (not just the same `A`
+    //   type): freeing memory through an allocator instance that did not
+    //   hand it out is a violation of `Allocator`'s safety contract. This is
+    //   trivially true for stateless allocators such as `Global` (every
+    //   instance of a ZST is interchangeable), but not in general (e.g. for
+    //   a handle into one of several distinct arenas) — we cannot check this
+    //   for an arbitrary `A`, since `Allocator` does not require `PartialEq`.
     #[inline]
     pub
     fn append (self: &'_ mut Self, other: Self)
     {
+        if other.head.is_none() {
+            // Nothing to splice in. Bail out before the non-empty-`self`
+            // branch below, which would otherwise unconditionally overwrite
+            // `self.last` with `other.last` (`None`), leaving `self.head`
+            // pointing at a chain that every other method would then treat
+            // as absent (silently leaking it on the next `push`). Letting
+            // the empty `other` drop normally here is enough.
+            return;
+        }
         if let Some(last) = self.last() {
-            let other = mem::ManuallyDrop::new(other);
+            let mut other = mem::ManuallyDrop::new(other);
             let prev_last_next = last.next.replace(other.head);
             self.last = other.last;
             debug_assert!(prev_last_next.is_none());
+            unsafe {
+                // # Safety
+                //
+                //   - every chunk `other` used to own has just been spliced into
+                //     `self`'s chain above, and `other`'s own destructor has been
+                //     suppressed by `ManuallyDrop`, so `other.alloc` is the only
+                //     field of it that still needs disposing of; dropping it in
+                //     place here (rather than leaking it) keeps this sound for
+                //     allocators whose `Drop` has side effects.
+                ptr::drop_in_place(&mut other.alloc);
+            }
         } else {
             let prev_self = mem::replace(self, other);
-            // prev_self is empty, so we skip its destructor as an optimization
+            // `prev_self` is empty (no chunks to walk), so letting it run its
+            // ordinary destructor is cheap, and (unlike `mem::forget`) it
+            // properly disposes of `prev_self.alloc`.
             debug_assert!(prev_self.head.is_none());
-            mem::forget(prev_self);
         }
     }
-    
+
     #[inline]
     pub
     fn iter<'a> (self: &'a Self) -> impl Iterator<Item = &'a T> + 'a
@@ -178,7 +259,7 @@ impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY> {
     }
 }
 
-impl<T, const CHUNK_CAPACITY: usize> Drop for List<T, CHUNK_CAPACITY> {
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Drop for List<T, CHUNK_CAPACITY, A> {
     #[inline]
     fn drop (self: &'_ mut Self)
     {
@@ -186,8 +267,8 @@ impl<T, const CHUNK_CAPACITY: usize> Drop for List<T, CHUNK_CAPACITY> {
             self.last = None; // No more aliasing.
         }
         let mut cursor = self.head;
-        while let Some(mut chunk) = cursor {
-            let chunk: &mut Chunk<_, CHUNK_CAPACITY> = unsafe { chunk.as_mut() };
+        while let Some(mut chunk_ptr) = cursor {
+            let chunk: &mut Chunk<_, CHUNK_CAPACITY> = unsafe { chunk_ptr.as_mut() };
             cursor = chunk.next.get();
             unsafe {
                 // # Safety
@@ -206,14 +287,14 @@ impl<T, const CHUNK_CAPACITY: usize> Drop for List<T, CHUNK_CAPACITY> {
                 // # Safety
                 //
                 //   - The safety invariant of `Self` relies on the chunks having been
-                //     `Box`-allocated.
-                drop(Box::from_raw(chunk));
+                //     allocated through `self.alloc`, with the layout of `Chunk`.
+                self.alloc.deallocate(chunk_ptr.cast(), Layout::new::<Chunk<T, CHUNK_CAPACITY>>());
             }
         }
     }
 }
 
-impl<T, const CHUNK_CAPACITY: usize> Extend<T> for List<T, CHUNK_CAPACITY> {
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Extend<T> for List<T, CHUNK_CAPACITY, A> {
     #[inline]
     fn extend<Iterable> (self: &'_ mut Self, iterable: Iterable)
     where
@@ -226,7 +307,25 @@ impl<T, const CHUNK_CAPACITY: usize> Extend<T> for List<T, CHUNK_CAPACITY> {
     }
 }
 
-impl<T, const CHUNK_CAPACITY: usize> FromIterator<T> for List<T, CHUNK_CAPACITY> {
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> List<T, CHUNK_CAPACITY, A> {
+    // Fallible counterpart to `Extend::extend`: stops at the first element whose
+    // `Chunk` allocation fails, leaving the list as it was right before that
+    // element (every earlier element has already been pushed).
+    pub
+    fn try_extend<Iterable> (self: &'_ mut Self, iterable: Iterable) -> Result<(), TryReserveError>
+    where
+        Iterable : IntoIterator<Item = T>,
+    {
+        for elem in iterable {
+            if let Err((_elem, err)) = self.try_push(elem) {
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize> FromIterator<T> for List<T, CHUNK_CAPACITY, Global> {
     #[inline]
     fn from_iter<Iterable> (iterable: Iterable) -> Self
     where
@@ -238,17 +337,418 @@ impl<T, const CHUNK_CAPACITY: usize> FromIterator<T> for List<T, CHUNK_CAPACITY>
     }
 }
 
-impl<'a, T : 'a, const CHUNK_CAPACITY: usize> IntoIterator for &'a List<T, CHUNK_CAPACITY> {
+impl<T, const CHUNK_CAPACITY: usize> List<T, CHUNK_CAPACITY, Global> {
+    // Fallible counterpart to `FromIterator::from_iter`.
+    pub
+    fn try_from_iter<Iterable> (iterable: Iterable) -> Result<Self, TryReserveError>
+    where
+        Iterable : IntoIterator<Item = T>,
+    {
+        let mut ret = Self::new();
+        ret.try_extend(iterable)?;
+        Ok(ret)
+    }
+}
+
+impl<'a, T : 'a, const CHUNK_CAPACITY: usize, A : Allocator> IntoIterator for &'a List<T, CHUNK_CAPACITY, A> {
     type Item = &'a T;
     type IntoIter = impl Iterator<Item = Self::Item> + 'a;
 
     #[inline]
-    fn into_iter (self: & 'a List<T, CHUNK_CAPACITY>) -> Self::IntoIter
+    fn into_iter (self: & 'a List<T, CHUNK_CAPACITY, A>) -> Self::IntoIter
     {
         self.iter()
     }
 }
 
+pub
+struct IntoIter<T, const CHUNK_CAPACITY: usize, A : Allocator = Global> {
+    cursor: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
+    idx: usize,
+    alloc: A,
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> IntoIterator for List<T, CHUNK_CAPACITY, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, CHUNK_CAPACITY, A>;
+
+    #[inline]
+    fn into_iter (self: Self) -> Self::IntoIter
+    {
+        let this = mem::ManuallyDrop::new(self);
+        IntoIter {
+            cursor: this.head,
+            idx: 0,
+            alloc: unsafe {
+                // # Safety
+                //
+                //   - `this`'s destructor has been suppressed by `ManuallyDrop`, and
+                //     `this` is never used again after this point, so reading `alloc`
+                //     out of it by value does not yield a double-use of it.
+                ptr::read(&this.alloc)
+            },
+        }
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Iterator for IntoIter<T, CHUNK_CAPACITY, A> {
+    type Item = T;
+
+    #[inline]
+    fn next (self: &'_ mut Self) -> Option<T>
+    {
+        loop {
+            let chunk_ptr = self.cursor?;
+            let chunk: &Chunk<T, CHUNK_CAPACITY> = unsafe {
+                // # Safety
+                //
+                //   - `cursor` is only ever set to pointers into chunks that are
+                //     still alive and owned by `self`.
+                chunk_ptr.as_ref()
+            };
+            let len = chunk.len.get();
+            if self.idx < len {
+                let elem = unsafe {
+                    // # Safety
+                    //
+                    //   - `idx < len`, so this slot holds an initialized `T` that
+                    //     has not been read yet (slots are only ever read once,
+                    //     in increasing `idx` order).
+                    ptr::read(chunk.buffer.get_unchecked(self.idx).get().cast::<T>())
+                };
+                self.idx += 1;
+                return Some(elem);
+            }
+            // This chunk is exhausted: every slot in it has been read out above,
+            // so only its own allocation remains to be freed.
+            self.cursor = chunk.next.get();
+            unsafe {
+                // # Safety
+                //
+                //   - The safety invariant of `List` relies on the chunks having
+                //     been allocated through `self.alloc`, with the layout of `Chunk`.
+                self.alloc.deallocate(chunk_ptr.cast(), Layout::new::<Chunk<T, CHUNK_CAPACITY>>());
+            }
+            self.idx = 0;
+        }
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> FusedIterator for IntoIter<T, CHUNK_CAPACITY, A> {}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Drop for IntoIter<T, CHUNK_CAPACITY, A> {
+    #[inline]
+    fn drop (self: &'_ mut Self)
+    {
+        while let Some(mut chunk_ptr) = self.cursor {
+            let chunk: &mut Chunk<T, CHUNK_CAPACITY> = unsafe { chunk_ptr.as_mut() };
+            self.cursor = chunk.next.get();
+            unsafe {
+                // # Safety
+                //
+                //   - `buffer[idx .. len]` are exactly the slots this iterator has
+                //     not yielded yet, so they still hold initialized, not-yet-dropped
+                //     `T`s; everything before `idx` has already been read out by `next`.
+                let ptr: *mut T = chunk.buffer.as_mut_ptr().cast::<T>().add(self.idx);
+                ptr::drop_in_place::<[T]>(
+                    slice::from_raw_parts_mut(ptr, chunk.len.get() - self.idx)
+                );
+            }
+            unsafe {
+                // # Safety
+                //
+                //   - Same reasoning as in `List`'s own `Drop` impl.
+                self.alloc.deallocate(chunk_ptr.cast(), Layout::new::<Chunk<T, CHUNK_CAPACITY>>());
+            }
+            self.idx = 0;
+        }
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> List<T, CHUNK_CAPACITY, A> {
+    // Removes every element for which `f` returns `false`, compacting the
+    // surviving ones into the earliest chunks so that the list never grows
+    // past what it already occupied.
+    #[inline]
+    pub
+    fn retain<F> (self: &'_ mut Self, mut f: F)
+    where
+        F : FnMut(&T) -> bool,
+    {
+        self.extract_if(|elem| !f(elem)).for_each(drop);
+    }
+
+    // Lazily removes the elements for which `f` returns `true`, yielding them
+    // one by one while compacting the surviving ones in place. See `ExtractIf`.
+    pub
+    fn extract_if<F> (self: &'_ mut Self, f: F) -> ExtractIf<'_, T, CHUNK_CAPACITY, A, F>
+    where
+        F : FnMut(&T) -> bool,
+    {
+        ExtractIf {
+            read: self.head,
+            read_idx: 0,
+            write: self.head,
+            write_idx: 0,
+            prev_kept: None,
+            unwinding: false,
+            list: self,
+            f,
+        }
+    }
+}
+
+// Drains `list` through a two-cursor in-place compaction: a "read" cursor
+// inspects each element in turn, and a "write" cursor (never ahead of "read")
+// is where the elements that are kept get compacted to. Chunks that end up
+// holding none of the surviving elements are freed and spliced out of the
+// `next` chain as soon as that becomes apparent.
+pub
+struct ExtractIf<'list, T, const CHUNK_CAPACITY: usize, A : Allocator, F>
+where
+    F : FnMut(&T) -> bool,
+{
+    list: &'list mut List<T, CHUNK_CAPACITY, A>,
+    read: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
+    read_idx: usize,
+    write: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
+    write_idx: usize,
+    // The last chunk that has been fully finalized as "kept" (`None` while
+    // the very first chunk is still being decided).
+    prev_kept: NullablePtr< Chunk<T, CHUNK_CAPACITY> >,
+    // Set right before calling `f`, and cleared right after it returns
+    // normally. If `Drop::drop` observes this still set, it knows it is
+    // running as unwind cleanup from a panic raised out of `f` itself (the
+    // element `f` was inspecting is still in place, at `read_idx`), and must
+    // not call back into `f` again: see `Drop`'s impl.
+    unwinding: bool,
+    f: F,
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator, F> ExtractIf<'_, T, CHUNK_CAPACITY, A, F>
+where
+    F : FnMut(&T) -> bool,
+{
+    // Called once `read` has been fully exhausted: fixes up the currently
+    // active write chunk (or frees it, if it never received anything) and
+    // updates `list.last` accordingly. Idempotent, so it is safe to call this
+    // both from `next` (on natural exhaustion) and from `Drop` (in case of
+    // early abandonment, where it is reached through repeated `next` calls).
+    fn finish (self: &'_ mut Self)
+    {
+        let write = match self.write.take() {
+            Some(it) => it,
+            // already finished, or `skip_exhausted_chunks` already freed the
+            // final chunk and fixed up `list.last` itself
+            None => return,
+        };
+        if self.write_idx == 0 {
+            // Nothing was ever written into this chunk: free it too.
+            unsafe {
+                // # Safety: same reasoning as in `List`'s own `Drop` impl; this
+                // chunk's buffer holds no live elements (everything it used to
+                // hold has already been read out, one way or another).
+                self.list.alloc.deallocate(write.cast(), Layout::new::<Chunk<T, CHUNK_CAPACITY>>());
+            }
+            match self.prev_kept {
+                Some(prev) => unsafe { prev.as_ref() }.next.set(None),
+                None => self.list.head = None,
+            }
+            self.list.last = self.prev_kept;
+        } else {
+            unsafe {
+                // # Safety: `write` is a live chunk, holding `write_idx` initialized,
+                // compacted-into-place elements.
+                write.as_ref()
+            }.len.set(self.write_idx);
+            self.list.last = Some(write);
+        }
+    }
+
+    // Skips over (and, when appropriate, frees) chunks that `read` has fully
+    // exhausted, until one with something left to inspect is found, or the
+    // list is exhausted. Shared between `next` and the panic-salvage path in
+    // `Drop`, neither of which calls `f` here.
+    fn skip_exhausted_chunks (self: &'_ mut Self)
+    {
+        while let Some(read_chunk_ptr) = self.read {
+            let read_chunk: &Chunk<T, CHUNK_CAPACITY> = unsafe {
+                // # Safety: valid pointer as part of the safety invariant of `List`.
+                read_chunk_ptr.as_ref()
+            };
+            if self.read_idx < read_chunk.len.get() {
+                break;
+            }
+            let next = read_chunk.next.get();
+            if self.write == Some(read_chunk_ptr) {
+                match self.write_idx {
+                    0 => {
+                        // This chunk never received a single kept element:
+                        // it is entirely empty, free it and splice it out.
+                        unsafe {
+                            self.list.alloc.deallocate(
+                                read_chunk_ptr.cast(),
+                                Layout::new::<Chunk<T, CHUNK_CAPACITY>>(),
+                            );
+                        }
+                        match self.prev_kept {
+                            Some(prev) => unsafe { prev.as_ref() }.next.set(next),
+                            None => self.list.head = next,
+                        }
+                        if next.is_none() {
+                            // That was the list's final chunk.
+                            self.list.last = self.prev_kept;
+                        }
+                        self.write = next;
+                    }
+                    write_idx if write_idx == CHUNK_CAPACITY => {
+                        // Filled up exactly as `read` catches up with it:
+                        // finalize it, nothing to free.
+                        read_chunk.len.set(CHUNK_CAPACITY);
+                        self.prev_kept = self.write;
+                        self.write = next;
+                        self.write_idx = 0;
+                    }
+                    _ => {
+                        // Still the active write target, partially filled:
+                        // it may yet receive more elements compacted in
+                        // from later chunks, so leave it be.
+                    }
+                }
+            } else {
+                // `write` is strictly behind `read` (the write cursor
+                // never runs ahead of the read cursor), so this chunk
+                // never received anything: free it and splice it out of
+                // the still-active write chunk's `next`.
+                unsafe {
+                    self.list.alloc.deallocate(
+                        read_chunk_ptr.cast(),
+                        Layout::new::<Chunk<T, CHUNK_CAPACITY>>(),
+                    );
+                }
+                let write_chunk_ptr = self.write.expect("`write` never runs ahead of `read`");
+                unsafe { write_chunk_ptr.as_ref() }.next.set(next);
+            }
+            self.read = next;
+            self.read_idx = 0;
+        }
+    }
+
+    // Compacts an already-read-out, already-decided-to-be-kept `value` into
+    // the write position and advances the write cursor. Shared between `next`
+    // and the panic-salvage path in `Drop`.
+    fn write_kept (self: &'_ mut Self, value: T)
+    {
+        let write_chunk_ptr = self.write.expect("`write` never runs ahead of `read`");
+        let write_chunk: &Chunk<T, CHUNK_CAPACITY> = unsafe { write_chunk_ptr.as_ref() };
+        unsafe {
+            // # Safety: `write_idx <= read_idx`, so this slot has already
+            // been read out (or is still a never-written, `MaybeUninit`
+            // slot of a chunk that used to be shorter); either way, no
+            // `T` gets dropped or overwritten by this write.
+            ptr::write(write_chunk.buffer.get_unchecked(self.write_idx).get().cast::<T>(), value);
+        }
+        self.write_idx += 1;
+        if self.write_idx == CHUNK_CAPACITY && self.write != self.read {
+            // `write`'s chunk is different from the chunk `read` is
+            // currently in, so `read` will never revisit it: finalize and
+            // advance `write` right away. (When `write == read`, the
+            // chunk-exhaustion handling above takes care of this instead,
+            // since `read_idx` reaches the same boundary in lockstep.)
+            write_chunk.len.set(CHUNK_CAPACITY);
+            self.prev_kept = self.write;
+            self.write = write_chunk.next.get();
+            self.write_idx = 0;
+        }
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator, F> Iterator for ExtractIf<'_, T, CHUNK_CAPACITY, A, F>
+where
+    F : FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next (self: &'_ mut Self) -> Option<T>
+    {
+        loop {
+            self.skip_exhausted_chunks();
+            let read_chunk_ptr = match self.read {
+                Some(it) => it,
+                None => {
+                    self.finish();
+                    return None;
+                }
+            };
+            let read_chunk: &Chunk<T, CHUNK_CAPACITY> = unsafe { read_chunk_ptr.as_ref() };
+            let slot = unsafe { read_chunk.buffer.get_unchecked(self.read_idx) }.get().cast::<T>();
+            self.unwinding = true;
+            let remove = (self.f)(unsafe {
+                // # Safety: `read_idx < len`, so this slot holds an initialized
+                // `T` that has not been read yet; nothing writes to it while
+                // `f` runs.
+                &*slot
+            });
+            self.unwinding = false;
+            let value = unsafe {
+                // # Safety: same slot as above. `read_idx` only advances once
+                // `f` has returned, so a panic from `f` leaves the slot counted
+                // as not yet visited, and it is read out (and dropped) once.
+                ptr::read(slot)
+            };
+            self.read_idx += 1;
+            if remove {
+                // Removed: hand it back to the caller.
+                return Some(value);
+            }
+            // Kept: compact it into the write position.
+            self.write_kept(value);
+        }
+    }
+}
+
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator, F> Drop for ExtractIf<'_, T, CHUNK_CAPACITY, A, F>
+where
+    F : FnMut(&T) -> bool,
+{
+    fn drop (self: &'_ mut Self)
+    {
+        if self.unwinding {
+            // We're running as unwind cleanup from a panic raised inside
+            // `f` itself (the element `f` was inspecting has not been read
+            // out of the list yet, since `read_idx` only advances once `f`
+            // returns). Calling back into `f` again here — as
+            // `self.next()` would — risks a second panic, which Rust turns
+            // into a process abort instead of a propagating unwind. So
+            // instead, salvage the rest of the list without invoking `f`
+            // again: treat every not-yet-visited element as kept, exactly
+            // as `retain`/`extract_if`'s own panic-safety guarantee requires
+            // ("elements are dropped in an unspecified order" only applies
+            // to the ones `f` actually got to decide on).
+            loop {
+                self.skip_exhausted_chunks();
+                let read_chunk_ptr = match self.read {
+                    Some(it) => it,
+                    None => break,
+                };
+                let read_chunk: &Chunk<T, CHUNK_CAPACITY> = unsafe { read_chunk_ptr.as_ref() };
+                let value = unsafe {
+                    // # Safety: same reasoning as in `next`.
+                    ptr::read(read_chunk.buffer.get_unchecked(self.read_idx).get().cast::<T>())
+                };
+                self.read_idx += 1;
+                self.write_kept(value);
+            }
+            self.finish();
+        } else {
+            // Run the rest of the compaction pass to completion, dropping
+            // (rather than yielding) any not-yet-visited element for which
+            // `f` still returns `true`.
+            while self.next().is_some() {}
+        }
+    }
+}
+
 /* == MARKER TRAITS & Safety ==
  * Since it is not possible to mutate a `List` through a _shared_ reference to it
  * (its interior mutability being there just for soundness _w.r.t._ aliasing due
@@ -259,34 +759,45 @@ impl<'a, T : 'a, const CHUNK_CAPACITY: usize> IntoIterator for &'a List<T, CHUNK
  * Moreover, there is no reason not to be `Send` either (why is `UnsafeCell` not Send?)
  */
 
-// We can delegate `RefUnWindSafe`-safety to its elements
-impl<T, const CHUNK_CAPACITY: usize> ::std::panic::RefUnwindSafe
-    for List<T, CHUNK_CAPACITY>
+// `RefUnwindSafe`/`UnwindSafe` are only implemented for `std` builds: they are
+// meaningless without `::std::panic::catch_unwind`, and `core` does not carry
+// them (unlike `Sync`/`Send`, which `core` does define).
+#[cfg(feature = "std")]
+// We can delegate `RefUnWindSafe`-safety to its elements (and to the allocator)
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> ::std::panic::RefUnwindSafe
+    for List<T, CHUNK_CAPACITY, A>
 where
     T : ::std::panic::RefUnwindSafe,
+    A : ::std::panic::RefUnwindSafe,
 {}
 
 // # Safety: As stated above, we can delegate `Sync`-safety to its elements given
-// the lack of public interior mutability.
-unsafe impl<T, const CHUNK_CAPACITY: usize> Sync
-    for List<T, CHUNK_CAPACITY>
+// the lack of public interior mutability (and to the allocator, which is the only
+// other field).
+unsafe impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Sync
+    for List<T, CHUNK_CAPACITY, A>
 where
     T : Sync,
+    A : Sync,
 {}
-// # Safety: As stated above, we can delegate `Send`-safety to its elements.
-unsafe impl<T, const CHUNK_CAPACITY: usize> Send
-    for List<T, CHUNK_CAPACITY>
+// # Safety: As stated above, we can delegate `Send`-safety to its elements (and to
+// the allocator).
+unsafe impl<T, const CHUNK_CAPACITY: usize, A : Allocator> Send
+    for List<T, CHUNK_CAPACITY, A>
 where
     T : Send,
+    A : Send,
 {}
 
+#[cfg(feature = "std")]
 // Can we delegate `UnwindSafe`-safety for its elements?
 // Since the only moment where custom panicking code runs in the middle of potentially
 // broken invariants is when `Drop` is run, it can CURRENTLY so be.
-impl<T, const CHUNK_CAPACITY: usize> ::std::panic::UnwindSafe
-    for List<T, CHUNK_CAPACITY>
+impl<T, const CHUNK_CAPACITY: usize, A : Allocator> ::std::panic::UnwindSafe
+    for List<T, CHUNK_CAPACITY, A>
 where
     T : ::std::panic::UnwindSafe,
+    A : ::std::panic::UnwindSafe,
 {}
 
 #[cfg(test)]
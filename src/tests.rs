@@ -28,6 +28,144 @@ fn basic ()
     })
 }
 
+#[test]
+fn into_iter ()
+{
+    const ELEMS: [i32; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    fn check_with_capacity<const CAPACITY: usize> (arena: &'_ LeakChecker)
+    {
+        dbg!(CAPACITY);
+        let list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        assert_eq!(
+            list.into_iter().map(|elem| elem.to_owned()).collect::<Vec<_>>(),
+            ELEMS,
+        );
+
+        // Abandoning the iterator partway through must not leak the
+        // not-yet-visited elements, nor double-free the ones already yielded.
+        let list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next().unwrap().to_owned(), ELEMS[0]);
+        drop(iter);
+    }
+    LeakChecker::with(|arena| {
+        check_with_capacity::<1>(arena);
+        check_with_capacity::<2>(arena);
+        check_with_capacity::<3>(arena);
+        check_with_capacity::<4>(arena);
+        check_with_capacity::<5>(arena);
+        check_with_capacity::<6>(arena);
+    })
+}
+
+#[test]
+fn retain ()
+{
+    const ELEMS: [i32; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    fn check_with_capacity<const CAPACITY: usize> (arena: &'_ LeakChecker)
+    {
+        dbg!(CAPACITY);
+        let mut list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        list.retain(|elem| elem.to_owned() % 2 == 0);
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            ELEMS.iter().copied().filter(|x| x % 2 == 0).collect::<Vec<_>>(),
+        );
+
+        // Removing everything must leave a well-formed, empty list behind.
+        let mut list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        list.retain(|_| false);
+        assert_eq!(list.iter().count(), 0);
+
+        // Keep an entire leading chunk untouched (so `write` fills it up in
+        // lockstep with `read`, i.e. `write == read` right when the chunk
+        // becomes full), then remove exactly one element past it. This
+        // exercises the "chunk fills up exactly as `read` catches up to it"
+        // transition rather than only ever alternating removals.
+        let mut list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        list.retain(|elem| elem.to_owned() != ELEMS[CAPACITY]);
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            ELEMS.iter().copied().filter(|&x| x != ELEMS[CAPACITY]).collect::<Vec<_>>(),
+        );
+
+        // Abandoning an `extract_if` partway through must still compact (and
+        // drop) whatever is left, without leaking or double-freeing anything.
+        let mut list: List<_, CAPACITY> =
+            ELEMS
+                .iter()
+                .map(|&x| arena.alloc(x))
+                .collect()
+        ;
+        {
+            let mut extracted = list.extract_if(|elem| elem.to_owned() % 2 == 0);
+            assert_eq!(extracted.next().unwrap().to_owned(), ELEMS[1]);
+        }
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            ELEMS.iter().copied().filter(|x| x % 2 != 0).collect::<Vec<_>>(),
+        );
+    }
+    LeakChecker::with(|arena| {
+        check_with_capacity::<1>(arena);
+        check_with_capacity::<2>(arena);
+        check_with_capacity::<3>(arena);
+        check_with_capacity::<4>(arena);
+        check_with_capacity::<5>(arena);
+        check_with_capacity::<6>(arena);
+    })
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn retain_panicking_predicate ()
+{
+    use ::std::panic::{catch_unwind, AssertUnwindSafe};
+
+    // Mirrors a predicate that isn't "self-healing": it panics outright
+    // instead of returning `false` once some not-yet-visited elements no
+    // longer satisfy it, so `Drop` is guaranteed to still have work left
+    // when the panic hits (several elements remain unvisited).
+    let mut list: List<i32, 2> = (0 .. 10).collect();
+    let unwound = catch_unwind(AssertUnwindSafe(|| {
+        list.retain(|&x| { assert!(x < 5, "boom"); true });
+    }));
+    assert!(unwound.is_err(), "the panic must propagate, not abort the process");
+
+    // `Drop` must have salvaged the not-yet-visited tail (5..10) by treating
+    // it as kept, without calling the predicate again (which panicked once
+    // already and would abort the process on a second panic during unwind).
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        (0 .. 10).collect::<Vec<_>>(),
+    );
+}
+
 #[test]
 fn append ()
 {
@@ -47,6 +185,150 @@ fn append ()
     })
 }
 
+#[test]
+fn append_empty_other ()
+{
+    let elems = Vec::from_iter(0 .. 5);
+    LeakChecker::with(|arena| {
+        let alloc =
+            |&x| arena.alloc(x)
+        ;
+        let mut list: List<_, 2> = elems.iter().map(alloc).collect();
+        list.append(List::new());
+        // Appending an empty list must leave `self` untouched: not just its
+        // visible contents, but its internal `last` bookkeeping too (an
+        // earlier bug overwrote `self.last` with the empty `other.last`,
+        // making every subsequent `push` think the list was empty and
+        // silently leak the whole existing chain instead of appending to it).
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            elems,
+        );
+        list.push(arena.alloc(5));
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            Vec::from_iter(0 .. 6),
+        );
+    })
+}
+
+#[test]
+fn append_with_custom_allocator ()
+{
+    use ::core::alloc::{AllocError, Allocator, Layout};
+    use ::core::ptr::NonNull;
+
+    // A stateful allocator (unlike `Global`, whose instances are all
+    // interchangeable): it tracks how many allocations it has handed out
+    // that have not yet been deallocated, so that appending two lists that
+    // share one instance of it can be checked not to leak or double-free
+    // through it.
+    #[derive(Clone, Copy)]
+    struct Tracking<'a> {
+        live: &'a Cell<usize>,
+    }
+    unsafe impl Allocator for Tracking<'_> {
+        fn allocate (self: &'_ Self, layout: Layout) -> Result<NonNull<[u8]>, AllocError>
+        {
+            let ptr = Global.allocate(layout)?;
+            self.live.set(self.live.get() + 1);
+            Ok(ptr)
+        }
+
+        unsafe fn deallocate (self: &'_ Self, ptr: NonNull<u8>, layout: Layout)
+        {
+            self.live.set(self.live.get() - 1);
+            Global.deallocate(ptr, layout);
+        }
+    }
+
+    let live = Cell::new(0_usize);
+    let tracking = Tracking { live: &live };
+    let mut elems1 = Vec::from_iter(0 .. 50);
+    let elems2 = Vec::from_iter(598 .. 650);
+    LeakChecker::with(|arena| {
+        let alloc =
+            |&x| arena.alloc(x)
+        ;
+        let mut list: List<_, 7, Tracking<'_>> = List::new_in(tracking);
+        list.extend(elems1.iter().map(alloc));
+        let mut other: List<_, 7, Tracking<'_>> = List::new_in(tracking);
+        other.extend(elems2.iter().map(alloc));
+        list.append(other);
+        elems1.extend(elems2);
+        assert_eq!(
+            list.iter().map(ToOwned::to_owned).collect::<Vec<_>>(),
+            elems1,
+        );
+        assert!(live.get() > 0, "the appended list should still own live chunks");
+        drop(list);
+        assert_eq!(live.get(), 0, "every chunk allocated through `tracking` must be freed through it");
+    })
+}
+
+#[test]
+fn try_push_and_try_extend_on_allocation_failure ()
+{
+    use ::core::alloc::{AllocError, Allocator, Layout};
+    use ::core::ptr::NonNull;
+
+    // An allocator that succeeds exactly `remaining` more times before
+    // failing every allocation after that, so the fallible surface
+    // (`try_push`/`try_extend`/`try_from_iter`) can be exercised against a
+    // real allocation failure instead of only ever running against `Global`.
+    #[derive(Clone, Copy)]
+    struct FailingAllocator<'a> {
+        remaining: &'a Cell<usize>,
+    }
+    unsafe impl Allocator for FailingAllocator<'_> {
+        fn allocate (self: &'_ Self, layout: Layout) -> Result<NonNull<[u8]>, AllocError>
+        {
+            match self.remaining.get().checked_sub(1) {
+                Some(left) => {
+                    self.remaining.set(left);
+                    Global.allocate(layout)
+                }
+                None => Err(AllocError),
+            }
+        }
+
+        unsafe fn deallocate (self: &'_ Self, ptr: NonNull<u8>, layout: Layout)
+        {
+            Global.deallocate(ptr, layout);
+        }
+    }
+
+    // `try_push` hands the element back, unchanged, on failure, and leaves
+    // the list exactly as it was (no partial chunk, no bookkeeping update).
+    let remaining = Cell::new(0);
+    let mut list: List<i32, 2, FailingAllocator<'_>> =
+        List::new_in(FailingAllocator { remaining: &remaining })
+    ;
+    let (elem, _err) = list.try_push(42).unwrap_err();
+    assert_eq!(elem, 42);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), Vec::<i32>::new());
+
+    // Once the list already holds elements, a failing `try_push` must not
+    // disturb what is already there.
+    remaining.set(1);
+    let mut list: List<i32, 1, FailingAllocator<'_>> =
+        List::new_in(FailingAllocator { remaining: &remaining })
+    ;
+    list.try_push(1).unwrap();
+    let (elem, _err) = list.try_push(2).unwrap_err();
+    assert_eq!(elem, 2);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1]);
+
+    // `try_extend` stops at the first element whose chunk allocation fails,
+    // leaving every element pushed before it intact.
+    remaining.set(2);
+    let mut list: List<i32, 1, FailingAllocator<'_>> =
+        List::new_in(FailingAllocator { remaining: &remaining })
+    ;
+    list.try_extend(0 .. 5).unwrap_err();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+}
+
 mod leak_checker {
     use ::core::cell::Cell;
 
@@ -105,6 +387,10 @@ mod leak_checker {
             impl<T : Clone> Drop for Ret<'_, T> {
                 fn drop (self: &'_ mut Self)
                 {
+                    // Without `std`, there is no way to tell whether we are
+                    // already unwinding, so the double-free check below always
+                    // runs (and may itself abort on an actual double panic).
+                    #[cfg(feature = "std")]
                     if ::std::thread::panicking() { return; }
                     assert_eq!(
                         self.slot.replace(false),
@@ -127,6 +413,7 @@ mod leak_checker {
         pub(in self)
         fn assert_no_leaks (self: &'_ Self)
         {
+            #[cfg(feature = "std")]
             if ::std::thread::panicking() { return; }
             assert_eq!(
                 self.allocated_slots